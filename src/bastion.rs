@@ -9,12 +9,13 @@
 use crate::child::{BastionChildren, BastionClosure, Message};
 use crate::config::BastionConfig;
 use crate::context::BastionContext;
+use crate::instrument::{self, Event, EventKind};
 use crate::messages::PoisonPill;
 use crate::runtime_manager::RuntimeManager;
-use crate::runtime_system::RuntimeSystem;
+use crate::runtime_system::{self, RuntimeSystem};
 use crate::supervisor::{SupervisionStrategy, Supervisor};
 use crate::tramp::Tramp;
-use crossbeam_channel::unbounded;
+use crossbeam_channel::{unbounded, Sender};
 use ego_tree::{NodeRef, Tree};
 use env_logger::Builder;
 use futures::future::poll_fn;
@@ -23,9 +24,8 @@ use log::LevelFilter;
 
 use std::mem;
 use std::panic::AssertUnwindSafe;
-use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::sync::Mutex;
+use std::sync::{Condvar, Mutex};
 use tokio::prelude::future::FutureResult;
 use tokio::prelude::*;
 use tokio::runtime::Runtime;
@@ -38,6 +38,23 @@ lazy_static! {
     /// Fault induced supervisors queue
     pub static ref FAULTED: Arc<Mutex<Vec<Supervisor>>> =
         Arc::new(Mutex::new(Vec::<Supervisor>::new()));
+
+    /// Flags whether the runtime should keep running, paired with a
+    /// `Condvar` so the main thread can park instead of busy-spinning while
+    /// it waits for a shutdown signal. Tripped by the Ctrl-C handler in
+    /// `runtime_shutdown_callback` and by `force_shutdown`.
+    static ref SHUTDOWN: Arc<(Mutex<bool>, Condvar)> = Arc::new((Mutex::new(true), Condvar::new()));
+
+    /// Set by `unstable_shutdown` only once it has actually taken the
+    /// `PLATFORM` lock and driven the runtime to completion. `unstable_shutdown`
+    /// leaks the `PLATFORM` mutex in its locked state (its guard is forgotten so
+    /// the transmute-copied `RuntimeSystem` isn't double-dropped), so once it
+    /// has run, `runtime_shutdown_callback` must not try to lock `PLATFORM`
+    /// again on wake or it deadlocks forever on the lock `unstable_shutdown`
+    /// already holds. If the lock attempt fails, this stays `false` so
+    /// `runtime_shutdown_callback` falls back to its own `shutdown_on_idle`
+    /// path on wake instead of skipping the runtime entirely.
+    static ref FORCED_SHUTDOWN: Arc<Mutex<bool>> = Arc::new(Mutex::new(false));
 }
 
 /// Runtime which holds the runtime configuration and implements methods for
@@ -54,6 +71,10 @@ impl Bastion {
     /// # Arguments
     /// * `config` - Platform configuration given for the instantiation
     ///
+    /// # Errors
+    /// Returns an error instead of panicking if `config.worker_threads` is
+    /// `Some(0)`, since a zero-sized executor can't schedule anything.
+    ///
     /// # Example
     /// ```
     ///# use bastion::prelude::*;
@@ -64,15 +85,29 @@ impl Bastion {
     ///let config = BastionConfig {
     ///    log_level: LevelFilter::Debug,
     ///    in_test: false,
+    ///    record_events: true,
+    ///    worker_threads: None,
+    ///    thread_name_prefix: None,
     ///};
     ///
-    ///Bastion::platform_from_config(config);
+    ///Bastion::platform_from_config(config).unwrap();
     ///# }
     /// ```
     ///
-    pub fn platform_from_config(config: BastionConfig) -> Self {
+    pub fn platform_from_config(config: BastionConfig) -> Result<Self, String> {
+        if config.worker_threads == Some(0) {
+            return Err("`worker_threads` must be non-zero".to_string());
+        }
+
         let log_builder = Builder::from_default_env();
 
+        instrument::set_epoch();
+        instrument::set_recording_enabled(config.record_events);
+        runtime_system::configure_worker_pool(
+            config.worker_threads,
+            config.thread_name_prefix.clone(),
+        );
+
         let mut platform = Bastion {
             config,
             log_builder,
@@ -87,7 +122,7 @@ impl Bastion {
             .is_test(platform.config.in_test)
             .init();
 
-        platform
+        Ok(platform)
     }
 
     /// Instantiates the platform with default configuration.
@@ -106,9 +141,13 @@ impl Bastion {
         let default_config = BastionConfig {
             log_level: LevelFilter::Info,
             in_test: false,
+            record_events: true,
+            worker_threads: None,
+            thread_name_prefix: None,
         };
 
         Bastion::platform_from_config(default_config)
+            .expect("default configuration is always valid")
     }
 
     ///
@@ -174,6 +213,89 @@ impl Bastion {
         ns
     }
 
+    // Looks up the node holding the supervisor identified by `urn`,
+    // descending the same way `traverse_registry` does when placing new
+    // supervisors.
+    fn find_node<'a>(root: NodeRef<'a, Supervisor>, urn: &str) -> Option<NodeRef<'a, Supervisor>> {
+        if root.value().urn == urn {
+            return Some(root);
+        }
+
+        for i in root.children() {
+            if let Some(found) = Bastion::find_node(i, urn) {
+                return Some(found);
+            }
+        }
+
+        None
+    }
+
+    // Recursively collects the `tx` of every live child rooted at `node`,
+    // skipping children whose `tx` has already been torn down.
+    fn collect_recipients(
+        node: NodeRef<Supervisor>,
+        recipients: &mut Vec<Sender<Box<dyn Message>>>,
+    ) {
+        for children in node.value().ctx.descendants.iter() {
+            if let Some(tx) = children.tx.as_ref() {
+                recipients.push(tx.clone());
+            }
+        }
+
+        for i in node.children() {
+            Bastion::collect_recipients(i, recipients);
+        }
+    }
+
+    // Shared implementation behind `Bastion::broadcast` and
+    // `Supervisor::broadcast`: locks the registry, collects every live
+    // child's `tx` rooted at the node matching `urn` (or the whole tree
+    // when `urn` is `None`), then fans the boxed message out, cloning it
+    // once per recipient.
+    fn broadcast_message(urn: Option<&str>, msg_box: Box<dyn Message>) {
+        let recipients = {
+            let runtime = PLATFORM.lock().unwrap();
+            let arcreg = runtime.registry.clone();
+            let registry = arcreg.lock().unwrap();
+
+            let node = match urn {
+                Some(urn) => Bastion::find_node(registry.root(), urn),
+                None => Some(registry.root()),
+            };
+
+            let mut recipients = Vec::new();
+            if let Some(node) = node {
+                Bastion::collect_recipients(node, &mut recipients);
+            }
+            recipients
+        };
+
+        for tx in recipients {
+            tx.send(objekt::clone_box(&*msg_box)).unwrap_or(());
+        }
+    }
+
+    ///
+    /// Sends `msg` to every live child in the whole supervision tree.
+    ///
+    /// The message is cloned with `objekt::clone_box` once per recipient
+    /// and delivered on each child's `tx` channel, so this works as a
+    /// shutdown signal, config reload, or pub/sub fan-out without having
+    /// to thread channel handles through every closure by hand.
+    ///
+    /// # Example
+    /// ```rust
+    ///# use bastion::prelude::*;
+    ///#
+    ///# fn main() {
+    ///#    Bastion::platform();
+    ///Bastion::broadcast("reload-config".to_string());
+    ///# }
+    /// ```
+    pub fn broadcast<M: Message>(msg: M) {
+        Bastion::broadcast_message(None, Box::new(msg));
+    }
+
     pub(crate) fn fault_recovery(given: Supervisor, message_box: Box<dyn Message>) {
         // Clone supervisor for trampoline bouncing
         let trampoline_spv = given.clone();
@@ -240,6 +362,17 @@ impl Bastion {
 
         debug!("Restart Needed for – {:?}", restart_needed);
 
+        restart_needed.iter().for_each(|children| {
+            instrument::record(
+                children.id.clone(),
+                EventKind::RestartTriggered,
+                format!(
+                    "restart triggered by {:?} strategy",
+                    trampoline_spv.strategy
+                ),
+            );
+        });
+
         Tramp::Traverse(restart_needed).execute(|desc| {
             let message_clone = objekt::clone_box(&*message_box);
             let spv = trampoline_spv.clone();
@@ -254,8 +387,16 @@ impl Bastion {
                         let message_box = objekt::clone_box(&*message_box);
                         let tx = children.tx.as_ref().unwrap().clone();
                         let rx = children.rx.clone().unwrap();
+                        let restarted_id = children.id.clone();
+                        let message_received_id = restarted_id.clone();
 
                         let f = future::lazy(move || {
+                            instrument::record(
+                                message_received_id,
+                                EventKind::MessageReceived,
+                                "message delivered to restarted child thunk".to_string(),
+                            );
+
                             bt(
                                 BastionContext {
                                     parent: Some(Box::new(spv.clone())),
@@ -273,6 +414,14 @@ impl Bastion {
                             |result| -> FutureResult<(), ()> {
                                 if let Err(err) = result {
                                     error!("Panic happened in restarted - {:?}", err);
+                                    instrument::record(
+                                        restarted_id.clone(),
+                                        EventKind::Panicked,
+                                        format!(
+                                            "panic in restarted child: {:?}",
+                                            err.downcast_ref::<&str>()
+                                        ),
+                                    );
                                     let fark = FAULTED.clone();
                                     let mut faulted_ones = fark.lock().unwrap();
                                     let faulted = faulted_ones.pop().unwrap();
@@ -394,10 +543,23 @@ impl Bastion {
             root_spv = root.clone();
         }
 
+        instrument::record(
+            ret_val.id.clone(),
+            EventKind::Spawned,
+            "child spawned under root supervisor".to_string(),
+        );
+
         let tx = ret_val.tx.as_ref().unwrap().clone();
         let rx = ret_val.rx.clone().unwrap();
+        let message_received_id = ret_val.id.clone();
 
         let f = future::lazy(move || {
+            instrument::record(
+                message_received_id,
+                EventKind::MessageReceived,
+                "message delivered to child thunk".to_string(),
+            );
+
             bt(
                 BastionContext {
                     parent: Some(Box::new(root_spv.clone())),
@@ -420,6 +582,11 @@ impl Bastion {
                 let mut rootn = registry.root_mut();
                 let mut root = rootn.value().clone();
 
+                instrument::record(
+                    if_killed.id.clone(),
+                    EventKind::Killed,
+                    "child pushed onto killed queue".to_string(),
+                );
                 root.ctx.killed.push(if_killed);
 
                 // Enable re-entrant code
@@ -439,6 +606,47 @@ impl Bastion {
 
         ret_val
     }
+
+    ///
+    /// Drains every lifecycle event recorded so far and returns them.
+    ///
+    /// Recording can be turned off with [`BastionConfig::record_events`] for
+    /// deployments that don't want the instrumentation overhead; when it's
+    /// off this always returns an empty `Vec`.
+    ///
+    /// # Example
+    /// ```rust
+    ///# use bastion::prelude::*;
+    ///#
+    ///# fn main() {
+    ///#    Bastion::platform();
+    ///let events = Bastion::drain_events();
+    ///# }
+    /// ```
+    pub fn drain_events() -> Vec<Event> {
+        instrument::drain()
+    }
+}
+
+impl Supervisor {
+    ///
+    /// Sends `msg` to every live child under this supervisor and any of
+    /// its nested supervisors, the same way [`Bastion::broadcast`] fans a
+    /// message out across the whole tree, but scoped to this subtree.
+    ///
+    /// # Example
+    /// ```rust
+    ///# use bastion::prelude::*;
+    ///#
+    ///# fn main() {
+    ///#    Bastion::platform();
+    ///let supervisor = Bastion::supervisor("background-worker", "fetcher-system");
+    ///supervisor.broadcast("reload-config".to_string());
+    ///# }
+    /// ```
+    pub fn broadcast<M: Message>(&self, msg: M) {
+        Bastion::broadcast_message(Some(&self.urn), Box::new(msg));
+    }
 }
 
 type Never = ();
@@ -451,23 +659,56 @@ impl RuntimeManager for Bastion {
                 let l: RuntimeSystem = mem::transmute_copy(&*lock_ptr);
                 l.runtime.shutdown_now().wait().unwrap();
                 mem::forget(lock_ptr);
+
+                *FORCED_SHUTDOWN.lock().unwrap() = true;
             }
         }
+
+        // Wake the main thread only after `FORCED_SHUTDOWN` is settled, so
+        // it never sees `false` while `PLATFORM` is already leaked locked.
+        let (lock, cvar) = &*SHUTDOWN.clone();
+        *lock.lock().unwrap() = false;
+        cvar.notify_all();
     }
 
     fn runtime_shutdown_callback() {
         let mut entered = tokio_executor::enter().expect("main thread_local runtime lock");
-        let running = Arc::new(AtomicBool::new(true));
-        let r = running.clone();
+
+        let shutdown = SHUTDOWN.clone();
+        let handler_shutdown = shutdown.clone();
         let _ = ctrlc::set_handler(move || {
-            r.store(false, Ordering::SeqCst);
+            let (lock, cvar) = &*handler_shutdown;
+            *lock.lock().unwrap() = false;
+            cvar.notify_all();
         })
         .unwrap();
+
         entered
             .block_on(poll_fn(|| {
-                while running.load(Ordering::SeqCst) {}
+                let (lock, cvar) = &*shutdown;
+                let running = lock.lock().unwrap();
+                let _idle = cvar.wait_while(running, |running| *running).unwrap();
                 CLOSE_OVER
             }))
             .expect("cannot shutdown");
+
+        if *FORCED_SHUTDOWN.lock().unwrap() {
+            // `unstable_shutdown` already drove the runtime to completion
+            // and left `PLATFORM` permanently locked to do so safely;
+            // locking it again here would deadlock.
+            return;
+        }
+
+        // The main thread has been woken up, drive the runtime to
+        // completion instead of leaving it dangling.
+        let ark = PLATFORM.clone();
+        let mut platform = ark.lock().unwrap();
+        let runtime = mem::replace(
+            &mut platform.runtime,
+            Runtime::new().expect("failed to build fallback runtime"),
+        );
+        drop(platform);
+
+        runtime.shutdown_on_idle().wait().unwrap();
     }
 }
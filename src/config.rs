@@ -0,0 +1,37 @@
+//!
+//!
+//! Runtime configuration for the platform.
+//!
+
+use log::LevelFilter;
+
+/// Configuration used to instantiate the [`Bastion`](crate::bastion::Bastion) platform.
+#[derive(Debug, Clone)]
+pub struct BastionConfig {
+    /// Log level used by the platform's logger.
+    pub log_level: LevelFilter,
+    /// Whether the platform is being instantiated inside tests.
+    pub in_test: bool,
+    /// Whether the instrumentation subsystem records lifecycle events.
+    ///
+    /// Defaults to `true` everywhere but is exposed so it can be switched
+    /// off in production deployments that don't want the bookkeeping
+    /// overhead.
+    pub record_events: bool,
+    /// Number of worker threads the runtime's executor is sized with.
+    ///
+    /// `None` falls back to the number of logical CPUs. Must not be
+    /// `Some(0)`; `Bastion::platform_from_config` rejects that with an
+    /// error rather than letting the executor panic on start-up.
+    pub worker_threads: Option<usize>,
+    /// Prefix worker threads are named with, so they show up distinctly in
+    /// external profilers and OS-level thread listings.
+    ///
+    /// The instrumentation subsystem's `thread_id` field reads back the
+    /// numeric suffix tokio appends to each named worker thread, so events
+    /// recorded on a pool started with this set can be correlated back to
+    /// the specific worker that produced them.
+    ///
+    /// `None` falls back to the runtime's default prefix.
+    pub thread_name_prefix: Option<String>,
+}
@@ -0,0 +1,156 @@
+//!
+//!
+//! Instrumentation subsystem for actor lifecycle events.
+//!
+//! The runtime used to only leave a trail of ad-hoc `debug!`/`error!` lines
+//! behind in `spawn` and `fault_recovery`. This module gives that trail a
+//! structured, queryable shape so users can reconstruct per-thread
+//! timelines and diagnose restart storms.
+//!
+
+use lazy_static::lazy_static;
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+
+lazy_static! {
+    /// Recorded runtime events, drained by `Bastion::drain_events`.
+    static ref EVENTS: Arc<Mutex<Vec<Event>>> = Arc::new(Mutex::new(Vec::new()));
+
+    /// Epoch timestamps are measured against, captured once at
+    /// `Bastion::platform_from_config`.
+    static ref EPOCH: Mutex<Option<Instant>> = Mutex::new(None);
+
+    /// Whether `record` actually pushes onto `EVENTS`. Controlled by
+    /// `BastionConfig::record_events`.
+    static ref RECORDING_ENABLED: Mutex<bool> = Mutex::new(true);
+}
+
+// Counts down from `usize::MAX` so fallback ids can never collide with a
+// name-derived worker index, which counts up from `0`.
+static THREAD_COUNTER: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+thread_local! {
+    static THREAD_ID: Cell<usize> = Cell::new(derive_thread_id());
+}
+
+/// Derives the current thread's instrumentation id from the numeric suffix
+/// tokio's executor appends to a named worker thread (e.g. a pool started
+/// with `thread_name_prefix: Some("bastion-worker-".into())` names its
+/// threads `"bastion-worker-0"`, `"bastion-worker-1"`, ...), so `thread_id`
+/// lines up with the configured pool's own worker index. Threads whose
+/// name has no trailing digits, or that have no name (e.g. the main
+/// thread, a test harness thread), fall back to a monotonic counter counting down from `usize::MAX`, so a fallback id
+/// can never collide with a name-derived one.
+fn derive_thread_id() -> usize {
+    thread::current()
+        .name()
+        .and_then(trailing_digits)
+        .unwrap_or_else(|| THREAD_COUNTER.fetch_sub(1, Ordering::SeqCst))
+}
+
+/// Parses the trailing run of ASCII digits in `name`, if any (e.g.
+/// `"bastion-worker-3"` -> `Some(3)`, `"main"` -> `None`).
+fn trailing_digits(name: &str) -> Option<usize> {
+    let digits_start = name.rfind(|c: char| !c.is_ascii_digit()).map_or(0, |i| i + 1);
+    if digits_start == name.len() {
+        return None;
+    }
+    name[digits_start..].parse().ok()
+}
+
+/// A single structured lifecycle event emitted by the runtime.
+#[derive(Debug, Clone)]
+pub struct Event {
+    /// Nanoseconds elapsed since the epoch captured at platform start-up.
+    pub timestamp: u64,
+    /// Identifier of the child the event concerns.
+    pub child_id: String,
+    /// Id of the thread the event was recorded on: a worker thread for
+    /// events recorded inside a child's task, the calling thread for
+    /// `Spawned`.
+    pub thread_id: usize,
+    /// What kind of lifecycle transition this event represents.
+    pub kind: EventKind,
+    /// Human readable description of the event.
+    pub desc: String,
+}
+
+/// The kind of lifecycle transition an [`Event`] represents.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventKind {
+    /// A child was spawned and registered in the tree.
+    Spawned,
+    /// A child received a message.
+    MessageReceived,
+    /// A child's thunk panicked.
+    Panicked,
+    /// A supervision strategy triggered a restart.
+    RestartTriggered,
+    /// A child was pushed onto its supervisor's killed queue.
+    Killed,
+}
+
+/// Captures the epoch all subsequent event timestamps are measured from.
+///
+/// Called once from `Bastion::platform_from_config`.
+pub(crate) fn set_epoch() {
+    *EPOCH.lock().unwrap() = Some(Instant::now());
+}
+
+/// Enables or disables recording, mirroring `BastionConfig::record_events`.
+pub(crate) fn set_recording_enabled(enabled: bool) {
+    *RECORDING_ENABLED.lock().unwrap() = enabled;
+}
+
+/// Id of the thread this is called on (see `derive_thread_id`).
+pub(crate) fn current_thread_id() -> usize {
+    THREAD_ID.with(|id| id.get())
+}
+
+/// Pushes an event onto the recorder unless recording has been disabled.
+pub(crate) fn record(child_id: String, kind: EventKind, desc: String) {
+    if !*RECORDING_ENABLED.lock().unwrap() {
+        return;
+    }
+
+    let timestamp = EPOCH
+        .lock()
+        .unwrap()
+        .map(|epoch| epoch.elapsed().as_nanos() as u64)
+        .unwrap_or(0);
+
+    EVENTS.lock().unwrap().push(Event {
+        timestamp,
+        child_id,
+        thread_id: current_thread_id(),
+        kind,
+        desc,
+    });
+}
+
+/// Drains and returns every event recorded so far, clearing the buffer.
+pub(crate) fn drain() -> Vec<Event> {
+    let mut events = EVENTS.lock().unwrap();
+    std::mem::replace(&mut *events, Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trailing_digits_parses_worker_suffix() {
+        assert_eq!(trailing_digits("bastion-worker-3"), Some(3));
+        assert_eq!(trailing_digits("tokio-runtime-worker-0"), Some(0));
+    }
+
+    #[test]
+    fn trailing_digits_rejects_names_without_a_numeric_suffix() {
+        assert_eq!(trailing_digits("main"), None);
+        assert_eq!(trailing_digits(""), None);
+    }
+}
@@ -0,0 +1,70 @@
+//!
+//!
+//! Runtime system backing the platform.
+//!
+//! Owns the supervision tree registry and the tokio executor that children
+//! and supervisors are scheduled onto.
+//!
+
+use crate::supervisor::Supervisor;
+
+use ego_tree::Tree;
+use lazy_static::lazy_static;
+use tokio::runtime::{Builder, Runtime};
+
+use std::sync::{Arc, Mutex};
+
+lazy_static! {
+    /// Worker pool sizing requested through `BastionConfig`, read the next
+    /// time `RuntimeSystem::start` builds the executor. Populated by
+    /// `Bastion::platform_from_config` before the platform's first use.
+    static ref WORKER_THREADS: Mutex<Option<usize>> = Mutex::new(None);
+
+    /// Prefix worker threads are named with, mirroring `WORKER_THREADS`.
+    static ref THREAD_NAME_PREFIX: Mutex<Option<String>> = Mutex::new(None);
+}
+
+/// Records the worker pool sizing requested by `BastionConfig` so the next
+/// `RuntimeSystem::start` picks it up.
+pub(crate) fn configure_worker_pool(
+    worker_threads: Option<usize>,
+    thread_name_prefix: Option<String>,
+) {
+    *WORKER_THREADS.lock().unwrap() = worker_threads;
+    *THREAD_NAME_PREFIX.lock().unwrap() = thread_name_prefix;
+}
+
+/// Holds the supervision tree registry and the tokio runtime children and
+/// supervisors are scheduled onto.
+pub struct RuntimeSystem {
+    /// Root of the supervision tree.
+    pub registry: Arc<Mutex<Tree<Supervisor>>>,
+    /// Executor children and supervisors are spawned onto.
+    pub runtime: Runtime,
+}
+
+impl RuntimeSystem {
+    /// Builds the runtime system.
+    ///
+    /// Sizes the worker pool from whatever `BastionConfig` handed to
+    /// `configure_worker_pool` before the platform's first use, falling
+    /// back to the number of logical CPUs when nothing was configured.
+    pub fn start() -> Self {
+        let mut builder = Builder::new();
+
+        if let Some(worker_threads) = *WORKER_THREADS.lock().unwrap() {
+            builder.core_threads(worker_threads);
+        }
+
+        if let Some(prefix) = THREAD_NAME_PREFIX.lock().unwrap().clone() {
+            builder.name_prefix(prefix);
+        }
+
+        let runtime = builder.build().expect("failed to build tokio runtime");
+
+        RuntimeSystem {
+            registry: Arc::new(Mutex::new(Tree::new(Supervisor::default()))),
+            runtime,
+        }
+    }
+}
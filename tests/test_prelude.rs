@@ -1,9 +1,11 @@
 #[cfg(test)]
 mod tests {
+    use bastion::instrument::EventKind;
     use bastion::prelude::*;
 
     use log::LevelFilter;
 
+    use std::sync::mpsc;
     use std::sync::Once;
     use std::{thread, time};
 
@@ -14,8 +16,11 @@ mod tests {
             let config = BastionConfig {
                 log_level: LevelFilter::Debug,
                 in_test: true,
+                record_events: true,
+                worker_threads: None,
+                thread_name_prefix: Some("bastion-test-worker-".to_string()),
             };
-            let _bastion = Bastion::platform_from_config(config);
+            let _bastion = Bastion::platform_from_config(config).unwrap();
         });
     }
 
@@ -49,4 +54,96 @@ mod tests {
 
         awaiting(10);
     }
+
+    #[test]
+    fn drain_events_records_spawn() {
+        init();
+
+        Bastion::spawn(
+            |_p, _msg| {
+                println!("root supervisor - spawn_at_root - drain_events");
+            },
+            "drain-events-probe".to_string(),
+        );
+
+        awaiting(10);
+
+        let events = Bastion::drain_events();
+        assert!(events.iter().any(|event| event.kind == EventKind::Spawned));
+    }
+
+    #[test]
+    fn broadcast_delivers_message_to_spawned_child() {
+        init();
+
+        let (tx, rx) = mpsc::channel();
+
+        Bastion::spawn(
+            move |context: BastionContext, msg: Box<dyn Message>| {
+                receive! { msg,
+                    String => |s| { let _ = tx.send(s); },
+                    _ => {}
+                }
+
+                // Rebind so the broadcast message, sent after this first
+                // run, still finds a listener on the channel.
+                context.hook();
+            },
+            0_i32,
+        );
+
+        awaiting(10);
+
+        Bastion::broadcast("broadcast-ping".to_string());
+
+        let received = rx
+            .recv_timeout(time::Duration::from_millis(200))
+            .expect("broadcast message was never delivered");
+        assert_eq!(received, "broadcast-ping");
+    }
+
+    #[test]
+    fn thread_id_distinguishes_caller_from_named_worker() {
+        init();
+
+        Bastion::spawn(
+            |_p, _msg| {
+                println!("root supervisor - spawn_at_root - thread_id");
+            },
+            "thread-id-probe".to_string(),
+        );
+
+        awaiting(10);
+
+        let events = Bastion::drain_events();
+
+        // `Spawned` is recorded by `Bastion::spawn` itself, on the calling
+        // (test) thread; `MessageReceived` is recorded inside the child's
+        // thunk, on the named worker thread the runtime scheduled it onto.
+        let caller_thread_id = events
+            .iter()
+            .find(|event| event.kind == EventKind::Spawned)
+            .map(|event| event.thread_id)
+            .expect("Spawned event was not recorded");
+        let worker_thread_id = events
+            .iter()
+            .find(|event| event.kind == EventKind::MessageReceived)
+            .map(|event| event.thread_id)
+            .expect("MessageReceived event was not recorded");
+
+        assert_ne!(caller_thread_id, worker_thread_id);
+    }
+
+    #[test]
+    fn platform_from_config_rejects_zero_worker_threads() {
+        let config = BastionConfig {
+            log_level: LevelFilter::Debug,
+            in_test: true,
+            record_events: true,
+            worker_threads: Some(0),
+            thread_name_prefix: None,
+        };
+
+        assert!(Bastion::platform_from_config(config).is_err());
+    }
 }